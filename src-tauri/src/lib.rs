@@ -1,11 +1,79 @@
-// HVAC Simulator — Tauri v2 Desktop Wrapper
+// HVAC Simulator — Tauri v2 Desktop + Mobile Wrapper
 // Minimal bootstrap with dialog and filesystem plugins
 
+mod materials;
+#[cfg(desktop)]
+mod menu;
+mod project;
+mod simulation;
+mod startup;
+#[cfg(desktop)]
+mod updater;
+#[cfg(desktop)]
+mod window_events;
+
+use materials::MaterialTable;
+use simulation::SimulationMutex;
+#[cfg(mobile)]
+use tauri::Manager;
+
+// The native menu, close-confirmation dialog, equivalent desktop-only window
+// chrome, and the updater don't apply on tablets; mobile gets a
+// touch-friendly window instead, set up in the `setup` hook below.
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let builder = tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
+        .plugin(project::init())
+        .manage(SimulationMutex::default())
+        .manage(MaterialTable::default());
+
+    #[cfg(desktop)]
+    let builder = builder
+        .plugin(tauri_plugin_updater::Builder::new().build())
+        .menu(|app| menu::build(app))
+        .on_menu_event(|app, event| menu::handle_event(app, event))
+        .on_window_event(|window, event| window_events::handle(window, event))
+        .invoke_handler(tauri::generate_handler![
+            simulation::step_simulation,
+            simulation::set_thermostat,
+            simulation::load_zones,
+            updater::check_for_update,
+            updater::install_update,
+        ]);
+
+    #[cfg(mobile)]
+    let builder = builder.invoke_handler(tauri::generate_handler![
+        simulation::step_simulation,
+        simulation::set_thermostat,
+        simulation::load_zones,
+    ]);
+
+    builder
+        .setup(|app| {
+            let handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                startup::warm_up(&handle).await;
+            });
+
+            #[cfg(desktop)]
+            {
+                let handle = app.handle().clone();
+                tauri::async_runtime::spawn(async move {
+                    updater::check_on_startup(&handle).await;
+                });
+            }
+
+            #[cfg(mobile)]
+            if let Some(main) = app.get_webview_window("main") {
+                // Field commissioning happens on tablets: start filling the
+                // screen rather than the desktop default windowed size.
+                let _ = main.maximize();
+            }
+
+            Ok(())
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
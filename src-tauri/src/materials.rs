@@ -0,0 +1,38 @@
+// Material property table, loaded into managed state at startup.
+//
+// Ships a small built-in table of material R-values. It holds no
+// psychrometric properties, and the thermal solver does not read it: zones
+// carry their own resistance and capacitance values.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// Thermal properties of a single building material.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaterialProperties {
+    pub name: String,
+    /// Thermal resistance per unit thickness, in (m²·K)/W per meter.
+    pub r_value_per_m: f64,
+}
+
+/// Managed app state holding the loaded material table.
+#[derive(Debug, Default)]
+pub struct MaterialTable(pub Mutex<Vec<MaterialProperties>>);
+
+/// Built-in material defaults shipped with the app.
+pub fn default_materials() -> Vec<MaterialProperties> {
+    vec![
+        MaterialProperties {
+            name: "drywall".into(),
+            r_value_per_m: 0.08,
+        },
+        MaterialProperties {
+            name: "fiberglass_batt".into(),
+            r_value_per_m: 2.5,
+        },
+        MaterialProperties {
+            name: "concrete".into(),
+            r_value_per_m: 0.01,
+        },
+    ]
+}
@@ -0,0 +1,39 @@
+// Native application menu.
+//
+// Builds the File / Simulation / View menus and forwards clicks to the
+// frontend as plain events, keeping the actual run/pause/export logic in
+// JS/TS where the rest of the UI state lives.
+
+use tauri::menu::{Menu, MenuBuilder, MenuEvent, SubmenuBuilder};
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Build the native File / Simulation / View menu bar.
+pub fn build<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let file_menu = SubmenuBuilder::new(app, "File")
+        .text("file-open", "Open…")
+        .text("file-save", "Save")
+        .separator()
+        .text("file-export-results", "Export Results…")
+        .build()?;
+
+    let simulation_menu = SubmenuBuilder::new(app, "Simulation")
+        .text("sim-run", "Run")
+        .text("sim-pause", "Pause")
+        .text("sim-step", "Step")
+        .separator()
+        .text("sim-reset", "Reset")
+        .build()?;
+
+    let view_menu = SubmenuBuilder::new(app, "View")
+        .text("view-units", "Units…")
+        .build()?;
+
+    MenuBuilder::new(app)
+        .items(&[&file_menu, &simulation_menu, &view_menu])
+        .build()
+}
+
+/// Forward a menu click to the frontend as a `menu:<id>` event.
+pub fn handle_event<R: Runtime>(app: &AppHandle<R>, event: MenuEvent) {
+    let _ = app.emit(&format!("menu:{}", event.id().as_ref()), ());
+}
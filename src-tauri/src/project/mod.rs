@@ -0,0 +1,112 @@
+// First-class HVAC project persistence, exposed as a dedicated Tauri plugin
+// registered alongside `tauri_plugin_fs`.
+//
+// Replaces ad-hoc filesystem reads with a typed, versioned project format
+// (see `schema`) plus CSV/JSON results export and recent-files tracking.
+
+mod recent;
+mod schema;
+
+use std::path::{Path, PathBuf};
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{AppHandle, Manager, Runtime};
+
+pub use schema::{ProjectFile, SolverSettings, CURRENT_SCHEMA_VERSION};
+
+use crate::simulation::SimulationMutex;
+
+/// Read and parse a project file without touching recent-files or simulation
+/// state. Shared by the `load_project` command and the startup warm-up,
+/// which reopens the last-used project the same way.
+pub(crate) fn read_project_file(path: &Path) -> Result<ProjectFile, String> {
+    let raw = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    schema::parse(&raw)
+}
+
+/// List recently opened projects, most recent first, without going through
+/// the `list_recent` command (for in-process callers like startup warm-up).
+pub(crate) fn recent_projects<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<PathBuf>, String> {
+    recent::list(app)
+}
+
+/// A single zone-temperature sample in an exported results time series.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ResultSample {
+    pub time_s: f64,
+    pub temperatures: Vec<f64>,
+    pub energy_j: Vec<f64>,
+}
+
+/// Save a project to `path` in the current schema version.
+#[tauri::command]
+fn save_project(app: AppHandle, path: PathBuf, project: ProjectFile) -> Result<(), String> {
+    let mut project = project;
+    project.version = CURRENT_SCHEMA_VERSION;
+    let raw = serde_json::to_string_pretty(&project).map_err(|e| e.to_string())?;
+    std::fs::write(&path, raw).map_err(|e| e.to_string())?;
+    recent::record(&app, &path)
+}
+
+/// Load a project from `path`, migrating older schema versions forward, and
+/// feed its zones/adjacencies/outdoor schedule into the running solver so it
+/// is actually ready to step afterwards.
+#[tauri::command]
+fn load_project(app: AppHandle, path: PathBuf) -> Result<ProjectFile, String> {
+    let project = read_project_file(&path)?;
+    recent::record(&app, &path)?;
+    crate::simulation::load_zones_into(
+        app.state::<SimulationMutex>().inner(),
+        project.zones.clone(),
+        project.adjacencies.clone(),
+        project.outdoor_schedule.clone(),
+    )?;
+    Ok(project)
+}
+
+/// Export a results time series to `path` as CSV or JSON, inferred from the
+/// requested `format` ("csv" or "json").
+#[tauri::command]
+fn export_results(path: PathBuf, format: String, results: Vec<ResultSample>) -> Result<(), String> {
+    match format.as_str() {
+        "json" => {
+            let raw = serde_json::to_string_pretty(&results).map_err(|e| e.to_string())?;
+            std::fs::write(&path, raw).map_err(|e| e.to_string())
+        }
+        "csv" => {
+            let mut csv = String::from("time_s,zone,temperature_c,energy_j\n");
+            for sample in &results {
+                for (zone, (&temp, &energy)) in sample
+                    .temperatures
+                    .iter()
+                    .zip(sample.energy_j.iter())
+                    .enumerate()
+                {
+                    csv.push_str(&format!(
+                        "{},{},{},{}\n",
+                        sample.time_s, zone, temp, energy
+                    ));
+                }
+            }
+            std::fs::write(&path, csv).map_err(|e| e.to_string())
+        }
+        other => Err(format!("unsupported export format: {other}")),
+    }
+}
+
+/// List recently opened projects, most recent first.
+#[tauri::command]
+fn list_recent(app: AppHandle) -> Result<Vec<PathBuf>, String> {
+    recent::list(&app)
+}
+
+/// Build the `hvac-project` plugin: save/load/export/recent-files commands.
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new("hvac-project")
+        .invoke_handler(tauri::generate_handler![
+            save_project,
+            load_project,
+            export_results,
+            list_recent,
+        ])
+        .build()
+}
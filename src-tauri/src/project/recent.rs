@@ -0,0 +1,45 @@
+// Recently-opened project tracking, persisted as a small JSON file in the
+// app's config directory so the list survives restarts.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+const RECENT_FILE: &str = "recent_projects.json";
+const MAX_RECENT: usize = 10;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RecentList {
+    paths: Vec<PathBuf>,
+}
+
+fn recent_file_path<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join(RECENT_FILE))
+}
+
+fn read_list<R: Runtime>(app: &AppHandle<R>) -> Result<RecentList, String> {
+    let path = recent_file_path(app)?;
+    if !path.exists() {
+        return Ok(RecentList::default());
+    }
+    let raw = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&raw).map_err(|e| e.to_string())
+}
+
+/// Move `path` to the front of the recent-files list, persisting the result.
+pub fn record<R: Runtime>(app: &AppHandle<R>, path: &Path) -> Result<(), String> {
+    let mut list = read_list(app)?;
+    list.paths.retain(|p| p != path);
+    list.paths.insert(0, path.to_path_buf());
+    list.paths.truncate(MAX_RECENT);
+
+    let raw = serde_json::to_string_pretty(&list).map_err(|e| e.to_string())?;
+    std::fs::write(recent_file_path(app)?, raw).map_err(|e| e.to_string())
+}
+
+/// Return the recent-files list, most recent first.
+pub fn list<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<PathBuf>, String> {
+    Ok(read_list(app)?.paths)
+}
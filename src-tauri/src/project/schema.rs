@@ -0,0 +1,115 @@
+// Versioned on-disk project schema.
+//
+// `ProjectFileV1` is kept around purely as a migration source; new fields
+// should only ever be added to the latest version, with a `migrate_*`
+// function bridging from the previous one so older saved files keep loading.
+
+use serde::{Deserialize, Serialize};
+
+use crate::simulation::{OutdoorSample, ZoneAdjacency, ZoneParams};
+
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// The current on-disk project format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFile {
+    pub version: u32,
+    pub name: String,
+    pub zones: Vec<ZoneParams>,
+    pub adjacencies: Vec<ZoneAdjacency>,
+    pub outdoor_schedule: Vec<OutdoorSample>,
+    pub solver: SolverSettings,
+}
+
+/// Solver configuration persisted with the project, introduced in v2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolverSettings {
+    pub dt_s: f64,
+}
+
+impl Default for SolverSettings {
+    fn default() -> Self {
+        Self { dt_s: 60.0 }
+    }
+}
+
+/// v1 project files predate `solver` settings; RC parameters and schedule are unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ProjectFileV1 {
+    version: u32,
+    name: String,
+    zones: Vec<ZoneParams>,
+    adjacencies: Vec<ZoneAdjacency>,
+    outdoor_schedule: Vec<OutdoorSample>,
+}
+
+/// Parse a project file, migrating it forward to `CURRENT_SCHEMA_VERSION` if needed.
+pub fn parse(raw: &str) -> Result<ProjectFile, String> {
+    let version: VersionProbe = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    match version.version {
+        CURRENT_SCHEMA_VERSION => serde_json::from_str(raw).map_err(|e| e.to_string()),
+        1 => {
+            let v1: ProjectFileV1 = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+            Ok(migrate_v1_to_v2(v1))
+        }
+        other => Err(format!("unsupported project schema version: {other}")),
+    }
+}
+
+#[derive(Deserialize)]
+struct VersionProbe {
+    version: u32,
+}
+
+fn migrate_v1_to_v2(v1: ProjectFileV1) -> ProjectFile {
+    ProjectFile {
+        version: CURRENT_SCHEMA_VERSION,
+        name: v1.name,
+        zones: v1.zones,
+        adjacencies: v1.adjacencies,
+        outdoor_schedule: v1.outdoor_schedule,
+        solver: SolverSettings::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v1_projects_with_default_solver_settings() {
+        let v1_json = r#"{
+            "version": 1,
+            "name": "Test House",
+            "zones": [],
+            "adjacencies": [],
+            "outdoor_schedule": []
+        }"#;
+
+        let project = parse(v1_json).unwrap();
+        assert_eq!(project.version, CURRENT_SCHEMA_VERSION);
+        assert_eq!(project.name, "Test House");
+        assert_eq!(project.solver.dt_s, SolverSettings::default().dt_s);
+    }
+
+    #[test]
+    fn parses_current_version_unchanged() {
+        let v2_json = r#"{
+            "version": 2,
+            "name": "Test House",
+            "zones": [],
+            "adjacencies": [],
+            "outdoor_schedule": [],
+            "solver": {"dt_s": 30.0}
+        }"#;
+
+        let project = parse(v2_json).unwrap();
+        assert_eq!(project.solver.dt_s, 30.0);
+    }
+
+    #[test]
+    fn rejects_unknown_schema_version() {
+        let json = r#"{"version": 99}"#;
+        assert!(parse(json).is_err());
+    }
+}
@@ -0,0 +1,462 @@
+// Lumped-capacitance RC thermal model for multi-zone HVAC simulation.
+//
+// Each zone is modeled as a node with thermal capacitance `capacitance` (J/K)
+// coupled to outdoor air through `resistance` (K/W), plus an HVAC heat input
+// `Q` (W) controlled by deadband thermostat logic. Adjacent zones exchange
+// heat through an additional resistance term. The state is advanced with a
+// classic fixed-step RK4 integrator for stability at larger `dt`.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+/// Thermal and equipment parameters for a single conditioned zone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneParams {
+    /// Thermal capacitance of the zone, in J/K.
+    pub capacitance: f64,
+    /// Resistance coupling the zone to outdoor air, in K/W.
+    pub resistance: f64,
+    /// Maximum heating capacity of the zone's equipment, in W.
+    pub capacity_w: f64,
+    /// Thermostat setpoint, in °C.
+    pub setpoint: f64,
+    /// Thermostat deadband width, in °C.
+    pub band: f64,
+}
+
+/// Thermal coupling between two adjacent zones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ZoneAdjacency {
+    pub zone_a: usize,
+    pub zone_b: usize,
+    /// Resistance between the two zones, in K/W.
+    pub resistance: f64,
+}
+
+/// A single point in the outdoor-temperature schedule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutdoorSample {
+    pub time_s: f64,
+    pub temp_c: f64,
+}
+
+/// Mutable simulation state, shared across command invocations via `tauri::State`.
+#[derive(Debug, Default)]
+pub struct SimulationState {
+    pub zones: Vec<ZoneParams>,
+    pub adjacencies: Vec<ZoneAdjacency>,
+    pub temperatures: Vec<f64>,
+    pub outdoor_schedule: Vec<OutdoorSample>,
+    pub zone_on: Vec<bool>,
+    pub time_s: f64,
+    pub energy_j: Vec<f64>,
+}
+
+pub struct SimulationMutex(pub Mutex<SimulationState>);
+
+impl Default for SimulationMutex {
+    fn default() -> Self {
+        Self(Mutex::new(SimulationState::default()))
+    }
+}
+
+/// Result returned to the frontend after advancing the simulation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StepResult {
+    pub time_s: f64,
+    pub temperatures: Vec<f64>,
+    pub energy_j: Vec<f64>,
+}
+
+/// A minimal single-zone building used to warm up the solver before a real
+/// project is loaded.
+pub fn default_zones() -> (Vec<ZoneParams>, Vec<ZoneAdjacency>, Vec<OutdoorSample>) {
+    let zones = vec![ZoneParams {
+        capacitance: 5.0e6,
+        resistance: 0.01,
+        capacity_w: 3000.0,
+        setpoint: 21.0,
+        band: 1.0,
+    }];
+    let outdoor_schedule = vec![OutdoorSample {
+        time_s: 0.0,
+        temp_c: 5.0,
+    }];
+    (zones, Vec::new(), outdoor_schedule)
+}
+
+/// Check that every adjacency references zones that actually exist.
+fn validate_adjacencies(zones: &[ZoneParams], adjacencies: &[ZoneAdjacency]) -> Result<(), String> {
+    for adj in adjacencies {
+        if adj.zone_a >= zones.len() || adj.zone_b >= zones.len() {
+            return Err(format!(
+                "adjacency ({}, {}) references an out-of-range zone for {} zones",
+                adj.zone_a,
+                adj.zone_b,
+                zones.len()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Replace the solver's zones/adjacencies/outdoor schedule and reset
+/// temperatures to each zone's setpoint, energy accumulators to zero, and
+/// the clock to zero. This is how real project data (or the built-in
+/// defaults) actually gets into the solver.
+fn apply_zones(
+    sim: &mut SimulationState,
+    zones: Vec<ZoneParams>,
+    adjacencies: Vec<ZoneAdjacency>,
+    outdoor_schedule: Vec<OutdoorSample>,
+) -> Result<(), String> {
+    validate_adjacencies(&zones, &adjacencies)?;
+    sim.temperatures = zones.iter().map(|z| z.setpoint).collect();
+    sim.zone_on = vec![false; zones.len()];
+    sim.energy_j = vec![0.0; zones.len()];
+    sim.time_s = 0.0;
+    sim.zones = zones;
+    sim.adjacencies = adjacencies;
+    sim.outdoor_schedule = outdoor_schedule;
+    Ok(())
+}
+
+/// Load zones/adjacencies/outdoor schedule (typically a `ProjectFile`'s) into
+/// the managed simulation state directly, for callers that already hold an
+/// `AppHandle` (e.g. the project plugin, or the startup warm-up) rather than
+/// a `tauri::State`.
+pub fn load_zones_into(
+    mutex: &SimulationMutex,
+    zones: Vec<ZoneParams>,
+    adjacencies: Vec<ZoneAdjacency>,
+    outdoor_schedule: Vec<OutdoorSample>,
+) -> Result<(), String> {
+    let mut sim = mutex.0.lock().map_err(|e| e.to_string())?;
+    apply_zones(&mut sim, zones, adjacencies, outdoor_schedule)
+}
+
+/// Load zones/adjacencies/outdoor schedule into the solver, sizing
+/// temperatures and energy accumulators to match. `project::load_project`
+/// already does this for the project it loads; call this before stepping
+/// with any other zone data.
+#[tauri::command]
+pub fn load_zones(
+    state: tauri::State<'_, SimulationMutex>,
+    zones: Vec<ZoneParams>,
+    adjacencies: Vec<ZoneAdjacency>,
+    outdoor_schedule: Vec<OutdoorSample>,
+) -> Result<(), String> {
+    let mut sim = state.0.lock().map_err(|e| e.to_string())?;
+    apply_zones(&mut sim, zones, adjacencies, outdoor_schedule)
+}
+
+/// Linearly interpolate the outdoor temperature schedule at time `t`.
+/// Clamps to the first/last sample outside the schedule's range.
+fn outdoor_temp_at(schedule: &[OutdoorSample], t: f64) -> f64 {
+    if schedule.is_empty() {
+        return 0.0;
+    }
+    if t <= schedule[0].time_s {
+        return schedule[0].temp_c;
+    }
+    for pair in schedule.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        if t >= a.time_s && t <= b.time_s {
+            let span = b.time_s - a.time_s;
+            let frac = if span.abs() < f64::EPSILON {
+                0.0
+            } else {
+                (t - a.time_s) / span
+            };
+            return a.temp_c + frac * (b.temp_c - a.temp_c);
+        }
+    }
+    schedule[schedule.len() - 1].temp_c
+}
+
+/// Apply deadband thermostat control to each zone, updating `zone_on` in place
+/// and returning the resulting heat input (W) for each zone.
+fn thermostat_outputs(zones: &[ZoneParams], temps: &[f64], zone_on: &mut [bool]) -> Vec<f64> {
+    zones
+        .iter()
+        .zip(temps.iter())
+        .zip(zone_on.iter_mut())
+        .map(|((zp, &temp), on)| {
+            let half_band = zp.band / 2.0;
+            if temp < zp.setpoint - half_band {
+                *on = true;
+            } else if temp > zp.setpoint + half_band {
+                *on = false;
+            }
+            if *on {
+                zp.capacity_w
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Evaluate dT/dt for every zone given the current temperatures, heat inputs,
+/// and outdoor temperature. Guards against a degenerate `R*C == 0` coupling.
+/// Assumes adjacencies have already been validated against `zones.len()`.
+fn derivatives(
+    zones: &[ZoneParams],
+    adjacencies: &[ZoneAdjacency],
+    temps: &[f64],
+    heat_w: &[f64],
+    t_out: f64,
+) -> Vec<f64> {
+    let mut d = vec![0.0; temps.len()];
+    for (i, zp) in zones.iter().enumerate() {
+        let rc = zp.resistance * zp.capacitance;
+        let rc = if rc.abs() < f64::EPSILON { f64::EPSILON } else { rc };
+        d[i] = (t_out - temps[i]) / rc + heat_w[i] / zp.capacitance.max(f64::EPSILON);
+    }
+    for adj in adjacencies {
+        let rc_ab = adj.resistance * zones[adj.zone_a].capacitance;
+        let rc_ba = adj.resistance * zones[adj.zone_b].capacitance;
+        let rc_ab = if rc_ab.abs() < f64::EPSILON { f64::EPSILON } else { rc_ab };
+        let rc_ba = if rc_ba.abs() < f64::EPSILON { f64::EPSILON } else { rc_ba };
+        d[adj.zone_a] += (temps[adj.zone_b] - temps[adj.zone_a]) / rc_ab;
+        d[adj.zone_b] += (temps[adj.zone_a] - temps[adj.zone_b]) / rc_ba;
+    }
+    d
+}
+
+/// One RK4 step's worth of solver output.
+struct StepOutcome {
+    temperatures: Vec<f64>,
+    zone_on: Vec<bool>,
+    energy_j: Vec<f64>,
+    time_s: f64,
+}
+
+/// Advance the given state by `dt` seconds using RK4, with the thermostat's
+/// on/off decision (and therefore `Q`) held constant over the step. Pure
+/// function of its inputs so it can be unit tested without a `tauri::State`.
+fn advance(
+    zones: &[ZoneParams],
+    adjacencies: &[ZoneAdjacency],
+    outdoor_schedule: &[OutdoorSample],
+    temperatures: &[f64],
+    zone_on: &[bool],
+    energy_j: &[f64],
+    time_s: f64,
+    dt: f64,
+) -> Result<StepOutcome, String> {
+    if dt <= 0.0 {
+        return Err("dt must be positive".into());
+    }
+    validate_adjacencies(zones, adjacencies)?;
+
+    let mut zone_on = zone_on.to_vec();
+    let heat_w = thermostat_outputs(zones, temperatures, &mut zone_on);
+
+    let y0 = temperatures.to_vec();
+    let t_out_a = outdoor_temp_at(outdoor_schedule, time_s);
+    let t_out_mid = outdoor_temp_at(outdoor_schedule, time_s + dt / 2.0);
+    let t_out_b = outdoor_temp_at(outdoor_schedule, time_s + dt);
+
+    let k1 = derivatives(zones, adjacencies, &y0, &heat_w, t_out_a);
+    let y2: Vec<f64> = y0.iter().zip(&k1).map(|(y, k)| y + 0.5 * dt * k).collect();
+    let k2 = derivatives(zones, adjacencies, &y2, &heat_w, t_out_mid);
+    let y3: Vec<f64> = y0.iter().zip(&k2).map(|(y, k)| y + 0.5 * dt * k).collect();
+    let k3 = derivatives(zones, adjacencies, &y3, &heat_w, t_out_mid);
+    let y4: Vec<f64> = y0.iter().zip(&k3).map(|(y, k)| y + dt * k).collect();
+    let k4 = derivatives(zones, adjacencies, &y4, &heat_w, t_out_b);
+
+    let mut temperatures = y0;
+    for i in 0..temperatures.len() {
+        temperatures[i] += (dt / 6.0) * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+    }
+
+    let mut energy_j = energy_j.to_vec();
+    for (i, q) in heat_w.iter().enumerate() {
+        energy_j[i] += q.abs() * dt;
+    }
+
+    Ok(StepOutcome {
+        temperatures,
+        zone_on,
+        energy_j,
+        time_s: time_s + dt,
+    })
+}
+
+/// Advance the simulation state by `dt` seconds using RK4.
+#[tauri::command]
+pub fn step_simulation(
+    state: tauri::State<'_, SimulationMutex>,
+    dt: f64,
+) -> Result<StepResult, String> {
+    let mut sim = state.0.lock().map_err(|e| e.to_string())?;
+    let outcome = advance(
+        &sim.zones,
+        &sim.adjacencies,
+        &sim.outdoor_schedule,
+        &sim.temperatures,
+        &sim.zone_on,
+        &sim.energy_j,
+        sim.time_s,
+        dt,
+    )?;
+
+    sim.temperatures = outcome.temperatures;
+    sim.zone_on = outcome.zone_on;
+    sim.energy_j = outcome.energy_j;
+    sim.time_s = outcome.time_s;
+
+    Ok(StepResult {
+        time_s: sim.time_s,
+        temperatures: sim.temperatures.clone(),
+        energy_j: sim.energy_j.clone(),
+    })
+}
+
+/// Update a zone's thermostat setpoint and deadband.
+#[tauri::command]
+pub fn set_thermostat(
+    state: tauri::State<'_, SimulationMutex>,
+    zone: usize,
+    setpoint: f64,
+    band: f64,
+) -> Result<(), String> {
+    let mut sim = state.0.lock().map_err(|e| e.to_string())?;
+    let zp = sim
+        .zones
+        .get_mut(zone)
+        .ok_or_else(|| format!("no such zone: {zone}"))?;
+    zp.setpoint = setpoint;
+    zp.band = band.max(0.0);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rk4_matches_exponential_decay_without_heating() {
+        // Single zone, R*C = 1, no heat input (setpoint far below the
+        // starting temperature so the deadband thermostat never turns on).
+        let zones = vec![ZoneParams {
+            capacitance: 1.0,
+            resistance: 1.0,
+            capacity_w: 0.0,
+            setpoint: -100.0,
+            band: 1.0,
+        }];
+        let schedule = vec![OutdoorSample {
+            time_s: 0.0,
+            temp_c: 0.0,
+        }];
+
+        let mut temperatures = vec![10.0];
+        let mut zone_on = vec![false];
+        let mut energy_j = vec![0.0];
+        let mut time_s = 0.0;
+        let dt = 0.1;
+
+        for _ in 0..10 {
+            let outcome = advance(
+                &zones,
+                &[],
+                &schedule,
+                &temperatures,
+                &zone_on,
+                &energy_j,
+                time_s,
+                dt,
+            )
+            .unwrap();
+            temperatures = outcome.temperatures;
+            zone_on = outcome.zone_on;
+            energy_j = outcome.energy_j;
+            time_s = outcome.time_s;
+        }
+
+        // Analytic solution of dT/dt = -T/(RC) from T0=10 at t=1.
+        let expected = 10.0 * (-1.0_f64).exp();
+        assert!((temperatures[0] - expected).abs() < 1e-4);
+        assert_eq!(energy_j[0], 0.0);
+    }
+
+    #[test]
+    fn thermostat_deadband_has_hysteresis() {
+        let zones = vec![ZoneParams {
+            capacitance: 1.0,
+            resistance: 1.0,
+            capacity_w: 500.0,
+            setpoint: 20.0,
+            band: 2.0,
+        }];
+        let mut on = vec![false];
+
+        // Above the upper threshold: stays off.
+        let q = thermostat_outputs(&zones, &[21.5], &mut on);
+        assert_eq!(q, vec![0.0]);
+        assert!(!on[0]);
+
+        // Below the lower threshold: turns on.
+        let q = thermostat_outputs(&zones, &[18.5], &mut on);
+        assert_eq!(q, vec![500.0]);
+        assert!(on[0]);
+
+        // Inside the deadband: holds its previous state (hysteresis).
+        let q = thermostat_outputs(&zones, &[20.0], &mut on);
+        assert_eq!(q, vec![500.0]);
+        assert!(on[0]);
+
+        // Above the upper threshold again: turns off.
+        let q = thermostat_outputs(&zones, &[21.5], &mut on);
+        assert_eq!(q, vec![0.0]);
+        assert!(!on[0]);
+    }
+
+    #[test]
+    fn outdoor_schedule_interpolates_and_clamps() {
+        let schedule = vec![
+            OutdoorSample {
+                time_s: 0.0,
+                temp_c: 0.0,
+            },
+            OutdoorSample {
+                time_s: 10.0,
+                temp_c: 10.0,
+            },
+        ];
+
+        assert_eq!(outdoor_temp_at(&schedule, -5.0), 0.0);
+        assert_eq!(outdoor_temp_at(&schedule, 5.0), 5.0);
+        assert_eq!(outdoor_temp_at(&schedule, 20.0), 10.0);
+    }
+
+    #[test]
+    fn advance_rejects_out_of_range_adjacency() {
+        let zones = vec![ZoneParams {
+            capacitance: 1.0,
+            resistance: 1.0,
+            capacity_w: 0.0,
+            setpoint: 0.0,
+            band: 1.0,
+        }];
+        let bad_adjacency = vec![ZoneAdjacency {
+            zone_a: 0,
+            zone_b: 5,
+            resistance: 1.0,
+        }];
+
+        let result = advance(
+            &zones,
+            &bad_adjacency,
+            &[],
+            &[0.0],
+            &[false],
+            &[0.0],
+            0.0,
+            1.0,
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// Background warm-up performed while the splashscreen is shown.
+//
+// Loads the material property table and the last-opened project (or, when
+// no recent project loads, the default building model) before handing off
+// to the main window, so the simulator's data is ready by the time the user can
+// interact with it. Keeping this off the main thread via
+// `tauri::async_runtime::spawn` stops large projects from freezing the UI
+// during startup.
+
+use tauri::{AppHandle, Manager};
+
+use crate::materials::MaterialTable;
+use crate::simulation::SimulationMutex;
+
+/// Load startup data in the background, then swap the splashscreen for the main window.
+pub async fn warm_up(app: &AppHandle) {
+    load_material_tables(app).await;
+
+    if !load_last_opened_project(app).await {
+        load_default_building_model(app).await;
+    }
+
+    if let Some(splash) = app.get_webview_window("splashscreen") {
+        let _ = splash.close();
+    }
+    if let Some(main) = app.get_webview_window("main") {
+        let _ = main.show();
+    }
+}
+
+/// Load the default building model used when no project is opened.
+async fn load_default_building_model(app: &AppHandle) {
+    let (zones, adjacencies, outdoor_schedule) = crate::simulation::default_zones();
+    let _ = crate::simulation::load_zones_into(
+        app.state::<SimulationMutex>().inner(),
+        zones,
+        adjacencies,
+        outdoor_schedule,
+    );
+}
+
+/// Load the built-in material property table into managed state.
+async fn load_material_tables(app: &AppHandle) {
+    if let Ok(mut table) = app.state::<MaterialTable>().0.lock() {
+        *table = crate::materials::default_materials();
+    }
+}
+
+/// Reopen the most recently used project, if any. Returns `true` if a
+/// project was actually loaded, so the caller can fall back to the default
+/// building model otherwise.
+async fn load_last_opened_project(app: &AppHandle) -> bool {
+    let Ok(recent) = crate::project::recent_projects(app) else {
+        return false;
+    };
+    let Some(path) = recent.first() else {
+        return false;
+    };
+    let Ok(project) = crate::project::read_project_file(path) else {
+        return false;
+    };
+    crate::simulation::load_zones_into(
+        app.state::<SimulationMutex>().inner(),
+        project.zones,
+        project.adjacencies,
+        project.outdoor_schedule,
+    )
+    .is_ok()
+}
@@ -0,0 +1,65 @@
+// Auto-update channel for new simulation-engine releases.
+//
+// Desktop-only: lets users pull corrected solver builds without a manual
+// reinstall. Checks run on startup in the background and again on demand
+// via the `check_for_update` command; `install_update` checks again and
+// downloads and installs the latest release it finds.
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_updater::UpdaterExt;
+
+/// Update availability reported to the frontend.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateStatus {
+    pub available: bool,
+    pub version: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Check for a newer release without installing it.
+#[tauri::command]
+pub async fn check_for_update<R: Runtime>(app: AppHandle<R>) -> Result<UpdateStatus, String> {
+    match app.updater().map_err(|e| e.to_string())?.check().await {
+        Ok(Some(update)) => Ok(UpdateStatus {
+            available: true,
+            version: Some(update.version),
+            notes: update.body,
+        }),
+        Ok(None) => Ok(UpdateStatus {
+            available: false,
+            version: None,
+            notes: None,
+        }),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Download and install the latest update, then restart the app.
+#[tauri::command]
+pub async fn install_update<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let update = app
+        .updater()
+        .map_err(|e| e.to_string())?
+        .check()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or("no update available")?;
+
+    update
+        .download_and_install(|_, _| {}, || {})
+        .await
+        .map_err(|e| e.to_string())?;
+
+    app.restart();
+}
+
+/// Background check performed at startup; emits `update:available` when a
+/// newer release is found so the frontend can prompt the user.
+pub async fn check_on_startup<R: Runtime>(app: &AppHandle<R>) {
+    if let Ok(status) = check_for_update(app.clone()).await {
+        if status.available {
+            let _ = app.emit("update:available", status);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Window-level event handling shared across all windows.
+//
+// Currently just the close-requested confirmation: an unsaved simulation
+// session shouldn't be lost to an accidental window close.
+
+use tauri::{Emitter, Listener, Manager, Runtime, WindowEvent};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+
+/// Event the frontend emits once its save (triggered by `menu:file-save`)
+/// has actually finished, so we know it's safe to tear the window down.
+const SAVE_COMPLETE_EVENT: &str = "project:save-complete";
+
+/// Prompt to save before closing a window that may hold unsaved simulation
+/// state. "Yes" asks the frontend to save and waits for `project:save-complete`
+/// before closing — emitting the save request is fire-and-forget, so closing
+/// right away could tear the window down before the save lands. "No" discards
+/// and closes immediately.
+///
+/// Both paths must call `window.destroy()`, not `window.close()`: `close()`
+/// just re-emits `CloseRequested`, which would re-enter this same handler
+/// and re-prompt forever instead of actually closing the window.
+pub fn handle<R: Runtime>(window: &tauri::Window<R>, event: &WindowEvent) {
+    if let WindowEvent::CloseRequested { api, .. } = event {
+        api.prevent_close();
+        let window = window.clone();
+        window
+            .app_handle()
+            .dialog()
+            .message("Save changes to this simulation?")
+            .title("Unsaved Changes")
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::YesNo)
+            .show(move |save| {
+                if save {
+                    let closer = window.clone();
+                    window.once(SAVE_COMPLETE_EVENT, move |_event| {
+                        let _ = closer.destroy();
+                    });
+                    let _ = window.emit("menu:file-save", ());
+                } else {
+                    let _ = window.destroy();
+                }
+            });
+    }
+}